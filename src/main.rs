@@ -2,13 +2,24 @@ use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use sysinfo::{CpuExt, SystemExt};
+use sysinfo::{ComponentExt, CpuExt, SystemExt};
 
 const DEFAULT_LEIBNIZ_ITERATIONS: u64 = 50_000_000;
 const DEFAULT_MONTE_CARLO_SAMPLES: u64 = 200_000_000;
+const THERMAL_SAMPLE_INTERVAL_MS: u64 = 250;
+const THROTTLE_FREQUENCY_DROP_THRESHOLD: f64 = 0.10;
+const DEFAULT_HARDWARE_BENCH_SECONDS: f64 = 1.0;
+const MEMORY_BUFFER_BYTES: usize = 128 * 1024 * 1024;
+const DISK_BLOCK_BYTES: usize = 1024 * 1024;
+const DISK_FLUSH_INTERVAL_BLOCKS: u32 = 16;
+const BYTES_PER_MIB: f64 = 1024.0 * 1024.0;
+const DEFAULT_REGRESSION_THRESHOLD_PERCENT: f64 = 5.0;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct BenchmarkResult {
@@ -16,12 +27,64 @@ struct BenchmarkResult {
     mode: String,
     work_label: String,
     work_units: u64,
+    reports_pi_estimate: Option<bool>,
     pi_estimate: f64,
     absolute_error: f64,
     elapsed_seconds: f64,
     throughput_per_second: f64,
     system: SystemProfile,
+    thermal: Option<ThermalProfile>,
+    secondary_label: Option<String>,
+    secondary_throughput_per_second: Option<f64>,
+    samples: Option<Vec<RunSample>>,
+    summary: Option<RunSummary>,
     notes: Option<String>,
+    digit_offset: Option<u64>,
+    hex_digits: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+struct RunSample {
+    pi_estimate: f64,
+    elapsed_seconds: f64,
+    throughput_per_second: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct RunSummary {
+    warmup_passes: usize,
+    measured_passes: usize,
+    elapsed_seconds: MetricSummary,
+    throughput_per_second: MetricSummary,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+struct MetricSummary {
+    min: f64,
+    max: f64,
+    mean: f64,
+    median: f64,
+    std_dev: f64,
+    coefficient_of_variation: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ThermalProfile {
+    sample_count: usize,
+    min_package_temp_c: Option<f32>,
+    max_package_temp_c: Option<f32>,
+    mean_package_temp_c: Option<f32>,
+    peak_component_temp_c: Option<f32>,
+    start_cpu_frequency_mhz: Option<u64>,
+    end_cpu_frequency_mhz: Option<u64>,
+    throttling_detected: bool,
+}
+
+#[derive(Clone, Copy)]
+struct ThermalSample {
+    package_temp_c: Option<f32>,
+    peak_temp_c: Option<f32>,
+    frequency_mhz: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -51,6 +114,12 @@ fn main() {
     let result = match mode.as_str() {
         "single" | "leibniz" => run_single_threaded(&args),
         "monte" | "monte-carlo" | "multi" | "multi-thread" => run_monte_carlo(&args),
+        "cpu" => run_cpu_benchmark(&args),
+        "memory" | "mem" => run_memory_benchmark(&args),
+        "disk" => run_disk_benchmark(&args),
+        "all" => run_all_benchmarks(&args),
+        "bbp" => run_bbp_benchmark(&args),
+        "compare" => run_compare(&args),
         _ => {
             eprintln!("Unknown mode: {mode}\n");
             print_global_usage();
@@ -67,6 +136,8 @@ fn main() {
 
 fn run_single_threaded(args: &[String]) -> Result<(), String> {
     let mut iterations = DEFAULT_LEIBNIZ_ITERATIONS;
+    let mut repeat: usize = 1;
+    let mut warmup: usize = 0;
     let mut json_output: Option<String> = None;
     let mut notes: Option<String> = None;
 
@@ -78,6 +149,16 @@ fn run_single_threaded(args: &[String]) -> Result<(), String> {
                 let value = args.get(i).ok_or("Missing value for --iterations")?;
                 iterations = parse_u64(value, "--iterations")?;
             }
+            "--repeat" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --repeat")?;
+                repeat = parse_usize(value, "--repeat")?;
+            }
+            "--warmup" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --warmup")?;
+                warmup = parse_usize(value, "--warmup")?;
+            }
             "--save-json" | "--json" | "--output-json" => {
                 i += 1;
                 json_output = Some(
@@ -102,48 +183,834 @@ fn run_single_threaded(args: &[String]) -> Result<(), String> {
     if iterations == 0 {
         return Err("Iterations must be greater than zero".into());
     }
+    if repeat == 0 {
+        return Err("Repeat must be at least 1".into());
+    }
 
-    let start = Instant::now();
-    let estimate = leibniz_pi(iterations);
-    let elapsed = start.elapsed();
+    let result = build_single_threaded_result(iterations, repeat, warmup, notes);
+
+    print_result_summary(&result);
+    save_result_if_requested(&result, json_output.as_deref())
+}
+
+fn build_single_threaded_result(
+    iterations: u64,
+    repeat: usize,
+    warmup: usize,
+    notes: Option<String>,
+) -> BenchmarkResult {
+    for _ in 0..warmup {
+        leibniz_pi(iterations);
+    }
+
+    let (passes, thermal) = sample_thermal_during(|| {
+        (0..repeat)
+            .map(|_| {
+                let start = Instant::now();
+                let estimate = leibniz_pi(iterations);
+                record_pass(iterations, start.elapsed(), estimate)
+            })
+            .collect::<Vec<RunSample>>()
+    });
 
-    let result = build_result(
+    build_result_from_passes(
         "Single-threaded Leibniz",
         "Iterations",
         iterations,
+        warmup,
+        passes,
+        thermal,
+        notes,
+    )
+}
+
+fn run_monte_carlo(args: &[String]) -> Result<(), String> {
+    let mut samples = DEFAULT_MONTE_CARLO_SAMPLES;
+    let mut threads: Option<usize> = None;
+    let mut seed: Option<u64> = None;
+    let mut repeat: usize = 1;
+    let mut warmup: usize = 0;
+    let mut json_output: Option<String> = None;
+    let mut notes: Option<String> = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--samples" | "-s" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --samples")?;
+                samples = parse_u64(value, "--samples")?;
+            }
+            "--threads" | "-t" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --threads")?;
+                threads = Some(parse_usize(value, "--threads")?);
+            }
+            "--seed" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --seed")?;
+                seed = Some(parse_u64(value, "--seed")?);
+            }
+            "--repeat" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --repeat")?;
+                repeat = parse_usize(value, "--repeat")?;
+            }
+            "--warmup" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --warmup")?;
+                warmup = parse_usize(value, "--warmup")?;
+            }
+            "--save-json" | "--json" | "--output-json" => {
+                i += 1;
+                json_output = Some(
+                    args.get(i)
+                        .ok_or("Missing value for --save-json")?
+                        .to_string(),
+                );
+            }
+            "--notes" => {
+                i += 1;
+                notes = Some(args.get(i).ok_or("Missing value for --notes")?.to_string());
+            }
+            "--help" | "-h" => {
+                print_monte_usage();
+                return Ok(());
+            }
+            other => return Err(format!("Unknown flag for monte mode: {other}")),
+        }
+        i += 1;
+    }
+
+    if samples == 0 {
+        return Err("Samples must be greater than zero".into());
+    }
+    if repeat == 0 {
+        return Err("Repeat must be at least 1".into());
+    }
+
+    let thread_count = threads.unwrap_or_else(default_thread_count);
+    if thread_count == 0 {
+        return Err("Thread count must be at least 1".into());
+    }
+
+    let base_seed = seed.unwrap_or_else(random_seed);
+
+    let result = build_monte_carlo_result(samples, thread_count, base_seed, repeat, warmup, notes);
+
+    print_result_summary(&result);
+    save_result_if_requested(&result, json_output.as_deref())
+}
+
+fn build_monte_carlo_result(
+    samples: u64,
+    thread_count: usize,
+    base_seed: u64,
+    repeat: usize,
+    warmup: usize,
+    notes: Option<String>,
+) -> BenchmarkResult {
+    for w in 0..warmup {
+        monte_carlo_pass(samples, thread_count, base_seed, w as u64);
+    }
+
+    let (passes, thermal) = sample_thermal_during(|| {
+        (0..repeat)
+            .map(|p| monte_carlo_pass(samples, thread_count, base_seed, (warmup + p) as u64))
+            .collect::<Vec<RunSample>>()
+    });
+
+    build_result_from_passes(
+        &format!("Monte Carlo ({} threads)", thread_count),
+        "Samples",
+        samples,
+        warmup,
+        passes,
+        thermal,
+        notes,
+    )
+}
+
+// `pass_index` perturbs each thread's seed so repeated passes sample different points.
+fn monte_carlo_pass(
+    samples: u64,
+    thread_count: usize,
+    base_seed: u64,
+    pass_index: u64,
+) -> RunSample {
+    let (per_thread, remainder) = split_work(samples, thread_count as u64);
+    let pass_seed = base_seed ^ (0xBF58_476D_1CE4_E5B9u64.wrapping_mul(pass_index + 1));
+
+    let start = Instant::now();
+
+    let mut handles = Vec::with_capacity(thread_count);
+    for idx in 0..thread_count {
+        let chunk = per_thread + u64::from(idx < remainder as usize);
+        let seed_for_thread = pass_seed ^ (0x9E37_79B9_7F4A_7C15u64.wrapping_mul(idx as u64 + 1));
+        handles.push(thread::spawn(move || {
+            monte_carlo_hits(chunk, seed_for_thread)
+        }));
+    }
+
+    let total_hits: u128 = handles
+        .into_iter()
+        .map(|h| u128::from(h.join().unwrap_or(0)))
+        .sum();
+
+    let elapsed = start.elapsed();
+    let estimate = 4.0 * (total_hits as f64) / (samples as f64);
+
+    record_pass(samples, elapsed, estimate)
+}
+
+fn run_cpu_benchmark(args: &[String]) -> Result<(), String> {
+    let hw_args = parse_hardware_args(args, print_cpu_usage)?;
+    let duration_secs = match hw_args.duration_secs {
+        Some(d) => d,
+        None => return Ok(()),
+    };
+    let (json_output, notes) = (hw_args.json_output, hw_args.notes);
+
+    let result = build_cpu_result(Duration::from_secs_f64(duration_secs), notes);
+
+    print_result_summary(&result);
+    save_result_if_requested(&result, json_output.as_deref())
+}
+
+fn build_cpu_result(duration: Duration, notes: Option<String>) -> BenchmarkResult {
+    let start = Instant::now();
+    let (ops, thermal) = sample_thermal_during(|| cpu_hash_kernel(duration));
+    let elapsed = start.elapsed();
+
+    build_hardware_result(
+        "CPU hashing kernel",
+        "Ops",
+        ops,
         elapsed,
-        estimate,
+        thermal,
+        None,
         notes,
+    )
+}
+
+fn cpu_hash_kernel(duration: Duration) -> u64 {
+    let start = Instant::now();
+    let mut acc: u64 = 0x9E37_79B9_7F4A_7C15;
+    let mut ops: u64 = 0;
+    while start.elapsed() < duration {
+        for _ in 0..4096 {
+            acc = acc.wrapping_mul(6364136223846793005).wrapping_add(1);
+            acc ^= acc >> 33;
+            acc = acc.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+            acc ^= acc >> 33;
+            ops += 1;
+        }
+    }
+    std::hint::black_box(acc);
+    ops
+}
+
+fn run_memory_benchmark(args: &[String]) -> Result<(), String> {
+    let hw_args = parse_hardware_args(args, print_memory_usage)?;
+    let duration_secs = match hw_args.duration_secs {
+        Some(d) => d,
+        None => return Ok(()),
+    };
+    let (json_output, notes) = (hw_args.json_output, hw_args.notes);
+
+    let result = build_memory_result(Duration::from_secs_f64(duration_secs), notes);
+
+    print_result_summary(&result);
+    save_result_if_requested(&result, json_output.as_deref())
+}
+
+fn build_memory_result(duration: Duration, notes: Option<String>) -> BenchmarkResult {
+    let ((bytes_moved, elapsed), thermal) =
+        sample_thermal_during(|| memory_bandwidth_kernel(duration));
+
+    build_hardware_result(
+        "Memory bandwidth (buffer copy)",
+        "Bytes",
+        bytes_moved,
+        elapsed,
+        thermal,
+        None,
+        notes,
+    )
+}
+
+// Returned `Duration` covers only the copy loop, not the buffer setup above it.
+fn memory_bandwidth_kernel(duration: Duration) -> (u64, Duration) {
+    let mut src = vec![0u8; MEMORY_BUFFER_BYTES];
+    let mut dst = vec![0u8; MEMORY_BUFFER_BYTES];
+    for (i, b) in src.iter_mut().enumerate() {
+        *b = (i % 256) as u8;
+    }
+
+    let start = Instant::now();
+    let mut bytes_moved: u64 = 0;
+    while start.elapsed() < duration {
+        dst.copy_from_slice(&src);
+        bytes_moved += MEMORY_BUFFER_BYTES as u64;
+    }
+    std::hint::black_box(&dst);
+    (bytes_moved, start.elapsed())
+}
+
+fn run_disk_benchmark(args: &[String]) -> Result<(), String> {
+    let hw_args = parse_hardware_args(args, print_disk_usage)?;
+    let duration_secs = match hw_args.duration_secs {
+        Some(d) => d,
+        None => return Ok(()),
+    };
+    let (json_output, notes) = (hw_args.json_output, hw_args.notes);
+
+    let result = build_disk_result(Duration::from_secs_f64(duration_secs), notes)?;
+
+    print_result_summary(&result);
+    save_result_if_requested(&result, json_output.as_deref())
+}
+
+fn build_disk_result(duration: Duration, notes: Option<String>) -> Result<BenchmarkResult, String> {
+    let path = temp_disk_bench_path();
+
+    let start = Instant::now();
+    let (write_result, thermal) = sample_thermal_during(|| disk_write_pass(&path, duration));
+    let write_elapsed = start.elapsed();
+
+    let bytes_written = write_result.inspect_err(|_| {
+        let _ = fs::remove_file(&path);
+    })?;
+
+    let read_outcome = disk_read_pass(&path);
+    let _ = fs::remove_file(&path);
+    let (bytes_read, read_elapsed) = read_outcome?;
+
+    let read_seconds = read_elapsed.as_secs_f64();
+    let read_mib_per_second = if read_seconds > 0.0 {
+        (bytes_read as f64 / BYTES_PER_MIB) / read_seconds
+    } else {
+        0.0
+    };
+
+    let write_mib = (bytes_written as f64 / BYTES_PER_MIB).round() as u64;
+
+    Ok(build_hardware_result(
+        "Disk sequential I/O",
+        "MiB written",
+        write_mib,
+        write_elapsed,
+        thermal,
+        Some(("MiB/s read".to_string(), read_mib_per_second)),
+        notes,
+    ))
+}
+
+fn temp_disk_bench_path() -> PathBuf {
+    env::temp_dir().join(format!("pi-benchmark-disk-{}.tmp", random_seed()))
+}
+
+fn disk_write_pass(path: &Path, duration: Duration) -> Result<u64, String> {
+    let mut file = fs::File::create(path)
+        .map_err(|e| format!("Could not create temp file {}: {e}", path.display()))?;
+
+    let mut rng_state = random_seed();
+    let mut block = vec![0u8; DISK_BLOCK_BYTES];
+    let start = Instant::now();
+    let mut bytes_written: u64 = 0;
+    let mut blocks_since_flush: u32 = 0;
+
+    while start.elapsed() < duration {
+        fill_pseudo_random_block(&mut block, &mut rng_state);
+        file.write_all(&block)
+            .map_err(|e| format!("Disk write failed: {e}"))?;
+        bytes_written += block.len() as u64;
+
+        blocks_since_flush += 1;
+        if blocks_since_flush >= DISK_FLUSH_INTERVAL_BLOCKS {
+            file.flush()
+                .map_err(|e| format!("Disk flush failed: {e}"))?;
+            blocks_since_flush = 0;
+        }
+    }
+
+    file.sync_all()
+        .map_err(|e| format!("Disk sync failed: {e}"))?;
+    Ok(bytes_written)
+}
+
+fn disk_read_pass(path: &Path) -> Result<(u64, Duration), String> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| format!("Could not open temp file {}: {e}", path.display()))?;
+    let mut buf = vec![0u8; DISK_BLOCK_BYTES];
+
+    let start = Instant::now();
+    let mut bytes_read: u64 = 0;
+    loop {
+        let n = file
+            .read(&mut buf)
+            .map_err(|e| format!("Disk read failed: {e}"))?;
+        if n == 0 {
+            break;
+        }
+        bytes_read += n as u64;
+    }
+
+    Ok((bytes_read, start.elapsed()))
+}
+
+fn fill_pseudo_random_block(buf: &mut [u8], state: &mut u64) {
+    let mut i = 0;
+    while i < buf.len() {
+        let bytes = next_unit_f64(state).to_bits().to_le_bytes();
+        let n = (buf.len() - i).min(bytes.len());
+        buf[i..i + n].copy_from_slice(&bytes[..n]);
+        i += n;
+    }
+}
+
+fn run_bbp_benchmark(args: &[String]) -> Result<(), String> {
+    let mut digit_offset: u64 = 0;
+    let mut digit_count: u64 = 16;
+    let mut json_output: Option<String> = None;
+    let mut notes: Option<String> = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--digit-offset" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --digit-offset")?;
+                digit_offset = parse_u64(value, "--digit-offset")?;
+            }
+            "--digit-count" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --digit-count")?;
+                digit_count = parse_u64(value, "--digit-count")?;
+            }
+            "--save-json" | "--json" | "--output-json" => {
+                i += 1;
+                json_output = Some(
+                    args.get(i)
+                        .ok_or("Missing value for --save-json")?
+                        .to_string(),
+                );
+            }
+            "--notes" => {
+                i += 1;
+                notes = Some(args.get(i).ok_or("Missing value for --notes")?.to_string());
+            }
+            "--help" | "-h" => {
+                print_bbp_usage();
+                return Ok(());
+            }
+            other => return Err(format!("Unknown flag for bbp mode: {other}")),
+        }
+        i += 1;
+    }
+
+    if digit_count == 0 {
+        return Err("Digit count must be greater than zero".into());
+    }
+
+    let result = build_bbp_result(digit_offset, digit_count, notes);
+
+    print_result_summary(&result);
+    save_result_if_requested(&result, json_output.as_deref())
+}
+
+fn build_bbp_result(digit_offset: u64, digit_count: u64, notes: Option<String>) -> BenchmarkResult {
+    let start = Instant::now();
+    let (hex_digits, thermal) = sample_thermal_during(|| {
+        (0..digit_count)
+            .map(|i| std::char::from_digit(bbp_hex_digit(digit_offset + i) as u32, 16).unwrap())
+            .collect::<String>()
+    });
+    let elapsed = start.elapsed();
+
+    let mut result = build_hardware_result(
+        "BBP hex digit extraction",
+        "Digits",
+        digit_count,
+        elapsed,
+        thermal,
+        None,
+        notes,
+    );
+    result.digit_offset = Some(digit_offset);
+    result.hex_digits = Some(hex_digits);
+    result
+}
+
+const BBP_EXTRA_TERMS: u64 = 20;
+
+fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut result: u128 = 1 % modulus;
+    let mut base = (base as u128) % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result * base) % modulus;
+        }
+        exp >>= 1;
+        base = (base * base) % modulus;
+    }
+    result as u64
+}
+
+// Computes S_j(d) = Σ_{k=0}^{d} frac(16^{d-k} mod (8k+j) / (8k+j)) + Σ_{k=d+1}^{d+K} 16^{d-k} / (8k+j).
+fn bbp_series_sum(d: u64, j: u64) -> f64 {
+    let mut sum = 0.0_f64;
+
+    for k in 0..=d {
+        let denom = 8 * k + j;
+        let remainder = mod_pow(16, d - k, denom);
+        sum += remainder as f64 / denom as f64;
+        sum -= sum.floor();
+    }
+
+    for k in (d + 1)..=(d + BBP_EXTRA_TERMS) {
+        let denom = 8 * k + j;
+        sum += 16f64.powi(-((k - d) as i32)) / denom as f64;
+    }
+
+    sum
+}
+
+// Bailey-Borwein-Plouffe digit extraction; `d` is 0-indexed from the first digit after "3.".
+fn bbp_hex_digit(d: u64) -> u8 {
+    let combined = 4.0 * bbp_series_sum(d, 1)
+        - 2.0 * bbp_series_sum(d, 4)
+        - bbp_series_sum(d, 5)
+        - bbp_series_sum(d, 6);
+    let fractional = combined - combined.floor();
+    (fractional * 16.0).floor() as u8
+}
+
+fn run_all_benchmarks(args: &[String]) -> Result<(), String> {
+    let hw_args = parse_hardware_args(args, print_all_usage)?;
+    let duration_secs = match hw_args.duration_secs {
+        Some(d) => d,
+        None => return Ok(()),
+    };
+    let (json_output, notes) = (hw_args.json_output, hw_args.notes);
+    let duration = Duration::from_secs_f64(duration_secs);
+
+    let mut results = Vec::new();
+    for result in [
+        build_single_threaded_result(DEFAULT_LEIBNIZ_ITERATIONS, 1, 0, notes.clone()),
+        build_monte_carlo_result(
+            DEFAULT_MONTE_CARLO_SAMPLES,
+            default_thread_count(),
+            random_seed(),
+            1,
+            0,
+            notes.clone(),
+        ),
+        build_cpu_result(duration, notes.clone()),
+        build_memory_result(duration, notes.clone()),
+        build_bbp_result(0, 16, notes.clone()),
+    ] {
+        print_result_summary(&result);
+        save_result_if_requested(&result, json_output.as_deref())?;
+        println!();
+        results.push(result);
+    }
+
+    match build_disk_result(duration, notes) {
+        Ok(disk) => {
+            print_result_summary(&disk);
+            save_result_if_requested(&disk, json_output.as_deref())?;
+            println!();
+            results.push(disk);
+        }
+        Err(e) => eprintln!("Disk benchmark failed: {e}"),
+    }
+
+    print_composite_hardware_profile(&results);
+    Ok(())
+}
+
+fn print_composite_hardware_profile(results: &[BenchmarkResult]) {
+    println!("Composite hardware profile");
+    for result in results {
+        println!(
+            "  {:<32}: {:.2} {}/s",
+            result.mode,
+            result.throughput_per_second,
+            result.work_label.to_lowercase()
+        );
+        if let (Some(label), Some(value)) = (
+            &result.secondary_label,
+            result.secondary_throughput_per_second,
+        ) {
+            println!("  {:<32}: {:.2} {}", "", value, label);
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum RegressionVerdict {
+    Pass,
+    Regression,
+}
+
+impl std::fmt::Display for RegressionVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegressionVerdict::Pass => write!(f, "PASS"),
+            RegressionVerdict::Regression => write!(f, "REGRESSION"),
+        }
+    }
+}
+
+struct Comparison {
+    mode: String,
+    cpu_model: Option<String>,
+    baseline_timestamp: String,
+    current_timestamp: String,
+    baseline_throughput: f64,
+    current_throughput: f64,
+    percent_change: f64,
+    verdict: RegressionVerdict,
+}
+
+fn run_compare(args: &[String]) -> Result<(), String> {
+    let mut history_path: Option<String> = None;
+    let mut baseline_timestamp: Option<String> = None;
+    let mut baseline_notes: Option<String> = None;
+    let mut threshold_percent = DEFAULT_REGRESSION_THRESHOLD_PERCENT;
+    let mut markdown = false;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--baseline" => {
+                i += 1;
+                baseline_timestamp = Some(
+                    args.get(i)
+                        .ok_or("Missing value for --baseline")?
+                        .to_string(),
+                );
+            }
+            "--baseline-notes" => {
+                i += 1;
+                baseline_notes = Some(
+                    args.get(i)
+                        .ok_or("Missing value for --baseline-notes")?
+                        .to_string(),
+                );
+            }
+            "--threshold" => {
+                i += 1;
+                let value = args.get(i).ok_or("Missing value for --threshold")?;
+                threshold_percent = parse_f64(value, "--threshold")?;
+            }
+            "--markdown" => markdown = true,
+            "--help" | "-h" => {
+                print_compare_usage();
+                return Ok(());
+            }
+            other if history_path.is_none() && !other.starts_with('-') => {
+                history_path = Some(other.to_string());
+            }
+            other => return Err(format!("Unknown flag for compare mode: {other}")),
+        }
+        i += 1;
+    }
+
+    let history_path = history_path.ok_or("compare requires a path to a JSON history file")?;
+    if !threshold_percent.is_finite() || threshold_percent <= 0.0 {
+        return Err("Threshold must be a finite number greater than zero".into());
+    }
+
+    let history = load_benchmark_history(&history_path)?;
+    if history.is_empty() {
+        return Err(format!("No benchmark results found in {history_path}"));
+    }
+
+    let comparisons = build_comparisons(
+        &history,
+        baseline_timestamp.as_deref(),
+        baseline_notes.as_deref(),
+        threshold_percent,
     );
+    if comparisons.is_empty() {
+        return Err(
+            "No comparisons could be made; need at least two runs for the same mode and CPU".into(),
+        );
+    }
+
+    if markdown {
+        print_comparisons_markdown(&comparisons, threshold_percent);
+    } else {
+        print_comparisons_table(&comparisons, threshold_percent);
+    }
+
+    if comparisons
+        .iter()
+        .any(|c| c.verdict == RegressionVerdict::Regression)
+    {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn build_comparisons(
+    history: &[BenchmarkResult],
+    baseline_timestamp: Option<&str>,
+    baseline_notes: Option<&str>,
+    threshold_percent: f64,
+) -> Vec<Comparison> {
+    let mut groups: Vec<(String, Option<String>, Vec<&BenchmarkResult>)> = Vec::new();
+    for result in history {
+        match groups.iter_mut().find(|(mode, cpu_model, _)| {
+            *mode == result.mode && *cpu_model == result.system.cpu_model
+        }) {
+            Some((_, _, entries)) => entries.push(result),
+            None => groups.push((
+                result.mode.clone(),
+                result.system.cpu_model.clone(),
+                vec![result],
+            )),
+        }
+    }
+
+    let mut comparisons = Vec::new();
+    for (mode, cpu_model, mut entries) in groups {
+        entries.sort_by(|a, b| a.timestamp_utc.cmp(&b.timestamp_utc));
+        let Some(current) = entries.last().copied() else {
+            continue;
+        };
+        let Some(baseline) = find_baseline(&entries, current, baseline_timestamp, baseline_notes)
+        else {
+            println!(
+                "Skipping {mode} ({}): no matching baseline run found",
+                cpu_model.as_deref().unwrap_or("unknown CPU")
+            );
+            continue;
+        };
+
+        let percent_change = percent_change(
+            baseline.throughput_per_second,
+            current.throughput_per_second,
+        );
+        let verdict = if percent_change <= -threshold_percent {
+            RegressionVerdict::Regression
+        } else {
+            RegressionVerdict::Pass
+        };
+
+        comparisons.push(Comparison {
+            mode,
+            cpu_model,
+            baseline_timestamp: baseline.timestamp_utc.clone(),
+            current_timestamp: current.timestamp_utc.clone(),
+            baseline_throughput: baseline.throughput_per_second,
+            current_throughput: current.throughput_per_second,
+            percent_change,
+            verdict,
+        });
+    }
+
+    comparisons
+}
+
+// Picks `--baseline <timestamp>`, else the latest run matching `--baseline-notes`, else the prior run.
+fn find_baseline<'a>(
+    entries: &[&'a BenchmarkResult],
+    current: &BenchmarkResult,
+    baseline_timestamp: Option<&str>,
+    baseline_notes: Option<&str>,
+) -> Option<&'a BenchmarkResult> {
+    if let Some(timestamp) = baseline_timestamp {
+        return entries
+            .iter()
+            .find(|r| r.timestamp_utc == timestamp)
+            .copied();
+    }
+
+    if let Some(notes) = baseline_notes {
+        return entries
+            .iter()
+            .rev()
+            .find(|r| r.timestamp_utc != current.timestamp_utc && r.notes.as_deref() == Some(notes))
+            .copied();
+    }
+
+    entries
+        .iter()
+        .rev()
+        .find(|r| r.timestamp_utc != current.timestamp_utc)
+        .copied()
+}
+
+fn percent_change(baseline: f64, current: f64) -> f64 {
+    if baseline == 0.0 {
+        0.0
+    } else {
+        (current - baseline) / baseline * 100.0
+    }
+}
+
+fn print_comparisons_table(comparisons: &[Comparison], threshold_percent: f64) {
+    println!("Regression comparison (threshold: {threshold_percent:.1}% throughput drop)");
+    println!();
+    for comparison in comparisons {
+        println!(
+            "{:<28} {:<20}: {:.2} -> {:.2} ({:+.2}%) [{}]",
+            comparison.mode,
+            comparison.cpu_model.as_deref().unwrap_or("unknown CPU"),
+            comparison.baseline_throughput,
+            comparison.current_throughput,
+            comparison.percent_change,
+            comparison.verdict
+        );
+        println!(
+            "  baseline {} -> current {}",
+            comparison.baseline_timestamp, comparison.current_timestamp
+        );
+    }
+}
+
+fn print_comparisons_markdown(comparisons: &[Comparison], threshold_percent: f64) {
+    println!("Regression comparison (threshold: {threshold_percent:.1}% throughput drop)");
+    println!();
+    println!("| Mode | CPU | Baseline | Current | Change | Verdict |");
+    println!("| --- | --- | --- | --- | --- | --- |");
+    for comparison in comparisons {
+        println!(
+            "| {} | {} | {:.2} | {:.2} | {:+.2}% | {} |",
+            comparison.mode,
+            comparison.cpu_model.as_deref().unwrap_or("unknown CPU"),
+            comparison.baseline_throughput,
+            comparison.current_throughput,
+            comparison.percent_change,
+            comparison.verdict
+        );
+    }
+}
 
-    print_result_summary(&result);
-    save_result_if_requested(&result, json_output.as_deref())
+struct HardwareArgs {
+    duration_secs: Option<f64>,
+    json_output: Option<String>,
+    notes: Option<String>,
 }
 
-fn run_monte_carlo(args: &[String]) -> Result<(), String> {
-    let mut samples = DEFAULT_MONTE_CARLO_SAMPLES;
-    let mut threads: Option<usize> = None;
-    let mut seed: Option<u64> = None;
+// `duration_secs` is `None` when `--help` was requested, so callers can return early.
+fn parse_hardware_args(args: &[String], print_usage: fn()) -> Result<HardwareArgs, String> {
+    let mut duration_secs = DEFAULT_HARDWARE_BENCH_SECONDS;
     let mut json_output: Option<String> = None;
     let mut notes: Option<String> = None;
 
     let mut i = 0;
     while i < args.len() {
         match args[i].as_str() {
-            "--samples" | "-s" => {
-                i += 1;
-                let value = args.get(i).ok_or("Missing value for --samples")?;
-                samples = parse_u64(value, "--samples")?;
-            }
-            "--threads" | "-t" => {
-                i += 1;
-                let value = args.get(i).ok_or("Missing value for --threads")?;
-                threads = Some(parse_usize(value, "--threads")?);
-            }
-            "--seed" => {
+            "--duration" => {
                 i += 1;
-                let value = args.get(i).ok_or("Missing value for --seed")?;
-                seed = Some(parse_u64(value, "--seed")?);
+                let value = args.get(i).ok_or("Missing value for --duration")?;
+                duration_secs = parse_f64(value, "--duration")?;
             }
             "--save-json" | "--json" | "--output-json" => {
                 i += 1;
@@ -158,56 +1025,27 @@ fn run_monte_carlo(args: &[String]) -> Result<(), String> {
                 notes = Some(args.get(i).ok_or("Missing value for --notes")?.to_string());
             }
             "--help" | "-h" => {
-                print_monte_usage();
-                return Ok(());
+                print_usage();
+                return Ok(HardwareArgs {
+                    duration_secs: None,
+                    json_output: None,
+                    notes: None,
+                });
             }
-            other => return Err(format!("Unknown flag for monte mode: {other}")),
+            other => return Err(format!("Unknown flag: {other}")),
         }
         i += 1;
     }
 
-    if samples == 0 {
-        return Err("Samples must be greater than zero".into());
-    }
-
-    let thread_count = threads.unwrap_or_else(default_thread_count);
-    if thread_count == 0 {
-        return Err("Thread count must be at least 1".into());
-    }
-
-    let base_seed = seed.unwrap_or_else(random_seed);
-    let (per_thread, remainder) = split_work(samples, thread_count as u64);
-
-    let start = Instant::now();
-
-    let mut handles = Vec::with_capacity(thread_count);
-    for idx in 0..thread_count {
-        let chunk = per_thread + u64::from(idx < remainder as usize);
-        let seed_for_thread = base_seed ^ (0x9E37_79B9_7F4A_7C15u64.wrapping_mul(idx as u64 + 1));
-        handles.push(thread::spawn(move || {
-            monte_carlo_hits(chunk, seed_for_thread)
-        }));
+    if !duration_secs.is_finite() || duration_secs <= 0.0 {
+        return Err("Duration must be a finite number greater than zero".into());
     }
 
-    let total_hits: u128 = handles
-        .into_iter()
-        .map(|h| u128::from(h.join().unwrap_or(0)))
-        .sum();
-
-    let elapsed = start.elapsed();
-    let estimate = 4.0 * (total_hits as f64) / (samples as f64);
-
-    let result = build_result(
-        &format!("Monte Carlo ({} threads)", thread_count),
-        "Samples",
-        samples,
-        elapsed,
-        estimate,
+    Ok(HardwareArgs {
+        duration_secs: Some(duration_secs),
+        json_output,
         notes,
-    );
-
-    print_result_summary(&result);
-    save_result_if_requested(&result, json_output.as_deref())
+    })
 }
 
 fn leibniz_pi(iterations: u64) -> f64 {
@@ -239,33 +1077,272 @@ fn next_unit_f64(state: &mut u64) -> f64 {
     f64::from_bits(bits) - 1.0
 }
 
-fn build_result(
+fn record_pass(work_units: u64, elapsed: Duration, estimate: f64) -> RunSample {
+    let elapsed_seconds = elapsed.as_secs_f64();
+    let throughput_per_second = if elapsed_seconds > 0.0 {
+        work_units as f64 / elapsed_seconds
+    } else {
+        0.0
+    };
+
+    RunSample {
+        pi_estimate: estimate,
+        elapsed_seconds,
+        throughput_per_second,
+    }
+}
+
+// Top-level fields report the mean across passes; the full distribution lives in `samples`/`summary`.
+fn build_result_from_passes(
+    mode: &str,
+    work_label: &str,
+    work_units: u64,
+    warmup_passes: usize,
+    passes: Vec<RunSample>,
+    thermal: Option<ThermalProfile>,
+    notes: Option<String>,
+) -> BenchmarkResult {
+    let estimates: Vec<f64> = passes.iter().map(|p| p.pi_estimate).collect();
+    let elapsed_summary =
+        summarize_metric(&passes.iter().map(|p| p.elapsed_seconds).collect::<Vec<_>>());
+    let throughput_summary = summarize_metric(
+        &passes
+            .iter()
+            .map(|p| p.throughput_per_second)
+            .collect::<Vec<_>>(),
+    );
+
+    let mean_estimate = estimates.iter().sum::<f64>() / estimates.len() as f64;
+    let error = (mean_estimate - std::f64::consts::PI).abs();
+    let measured_passes = passes.len();
+
+    BenchmarkResult {
+        timestamp_utc: current_timestamp(),
+        mode: mode.to_string(),
+        work_label: work_label.to_string(),
+        work_units,
+        reports_pi_estimate: Some(true),
+        pi_estimate: mean_estimate,
+        absolute_error: error,
+        elapsed_seconds: elapsed_summary.mean,
+        throughput_per_second: throughput_summary.mean,
+        system: collect_system_profile(),
+        thermal,
+        secondary_label: None,
+        secondary_throughput_per_second: None,
+        samples: Some(passes),
+        summary: Some(RunSummary {
+            warmup_passes,
+            measured_passes,
+            elapsed_seconds: elapsed_summary,
+            throughput_per_second: throughput_summary,
+        }),
+        notes,
+        digit_offset: None,
+        hex_digits: None,
+    }
+}
+
+// `values` must be non-empty.
+fn summarize_metric(values: &[f64]) -> MetricSummary {
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    };
+
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / count;
+    let std_dev = variance.sqrt();
+    let coefficient_of_variation = if mean != 0.0 { std_dev / mean } else { 0.0 };
+
+    MetricSummary {
+        min: sorted[0],
+        max: sorted[sorted.len() - 1],
+        mean,
+        median,
+        std_dev,
+        coefficient_of_variation,
+    }
+}
+
+// `pi_estimate`/`absolute_error` are left at zero; `secondary` is an optional second throughput figure.
+fn build_hardware_result(
     mode: &str,
     work_label: &str,
     work_units: u64,
     elapsed: Duration,
-    estimate: f64,
+    thermal: Option<ThermalProfile>,
+    secondary: Option<(String, f64)>,
     notes: Option<String>,
 ) -> BenchmarkResult {
-    let error = (estimate - std::f64::consts::PI).abs();
     let elapsed_seconds = elapsed.as_secs_f64();
     let throughput = if elapsed_seconds > 0.0 {
         work_units as f64 / elapsed_seconds
     } else {
         0.0
     };
+    let (secondary_label, secondary_throughput_per_second) = match secondary {
+        Some((label, value)) => (Some(label), Some(value)),
+        None => (None, None),
+    };
 
     BenchmarkResult {
         timestamp_utc: current_timestamp(),
         mode: mode.to_string(),
         work_label: work_label.to_string(),
         work_units,
-        pi_estimate: estimate,
-        absolute_error: error,
+        reports_pi_estimate: Some(false),
+        pi_estimate: 0.0,
+        absolute_error: 0.0,
         elapsed_seconds,
         throughput_per_second: throughput,
         system: collect_system_profile(),
+        thermal,
+        secondary_label,
+        secondary_throughput_per_second,
+        samples: None,
+        summary: None,
         notes,
+        digit_offset: None,
+        hex_digits: None,
+    }
+}
+
+// Runs `work` while a background thread polls temperature/frequency every `THERMAL_SAMPLE_INTERVAL_MS`.
+fn sample_thermal_during<F, R>(work: F) -> (R, Option<ThermalProfile>)
+where
+    F: FnOnce() -> R,
+{
+    let stop = Arc::new(AtomicBool::new(false));
+    let samples = Arc::new(Mutex::new(Vec::new()));
+
+    let sampler = {
+        let stop = Arc::clone(&stop);
+        let samples = Arc::clone(&samples);
+        thread::spawn(move || {
+            let mut sys = sysinfo::System::new();
+            while !stop.load(Ordering::Relaxed) {
+                sys.refresh_components_list();
+                sys.refresh_components();
+                sys.refresh_cpu();
+                samples.lock().unwrap().push(take_thermal_sample(&sys));
+                thread::sleep(Duration::from_millis(THERMAL_SAMPLE_INTERVAL_MS));
+            }
+        })
+    };
+
+    let result = work();
+
+    stop.store(true, Ordering::Relaxed);
+    let _ = sampler.join();
+
+    let collected = Arc::try_unwrap(samples)
+        .map(|m| m.into_inner().unwrap_or_default())
+        .unwrap_or_default();
+
+    (result, summarize_thermal(&collected))
+}
+
+fn take_thermal_sample(sys: &sysinfo::System) -> ThermalSample {
+    let temps: Vec<f32> = sys.components().iter().map(|c| c.temperature()).collect();
+    let package_temp_c = if temps.is_empty() {
+        None
+    } else {
+        Some(temps.iter().sum::<f32>() / temps.len() as f32)
+    };
+    let peak_temp_c = temps.iter().copied().fold(None, |acc: Option<f32>, t| {
+        Some(acc.map_or(t, |m| m.max(t)))
+    });
+
+    let frequency_mhz = if sys.cpus().is_empty() {
+        None
+    } else {
+        let total: u64 = sys.cpus().iter().map(|cpu| cpu.frequency()).sum();
+        Some(total / sys.cpus().len() as u64)
+    };
+
+    ThermalSample {
+        package_temp_c,
+        peak_temp_c,
+        frequency_mhz,
+    }
+}
+
+fn summarize_thermal(samples: &[ThermalSample]) -> Option<ThermalProfile> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let package_temps: Vec<f32> = samples.iter().filter_map(|s| s.package_temp_c).collect();
+    let min_package_temp_c = package_temps.iter().copied().fold(None, min_f32);
+    let max_package_temp_c = package_temps.iter().copied().fold(None, max_f32);
+    let mean_package_temp_c = if package_temps.is_empty() {
+        None
+    } else {
+        Some(package_temps.iter().sum::<f32>() / package_temps.len() as f32)
+    };
+    let peak_component_temp_c = samples
+        .iter()
+        .filter_map(|s| s.peak_temp_c)
+        .fold(None, max_f32);
+
+    let start_cpu_frequency_mhz = samples.first().and_then(|s| s.frequency_mhz);
+    let end_cpu_frequency_mhz = samples.last().and_then(|s| s.frequency_mhz);
+
+    Some(ThermalProfile {
+        sample_count: samples.len(),
+        min_package_temp_c,
+        max_package_temp_c,
+        mean_package_temp_c,
+        peak_component_temp_c,
+        start_cpu_frequency_mhz,
+        end_cpu_frequency_mhz,
+        throttling_detected: detect_throttling(samples),
+    })
+}
+
+fn min_f32(acc: Option<f32>, t: f32) -> Option<f32> {
+    Some(acc.map_or(t, |m| m.min(t)))
+}
+
+fn max_f32(acc: Option<f32>, t: f32) -> Option<f32> {
+    Some(acc.map_or(t, |m| m.max(t)))
+}
+
+// Compares mean frequency in the first vs. last quarter of samples; needs at least four.
+fn detect_throttling(samples: &[ThermalSample]) -> bool {
+    let quarter = samples.len() / 4;
+    if quarter == 0 {
+        return false;
+    }
+
+    let mean_freq = |slice: &[ThermalSample]| -> Option<f64> {
+        let freqs: Vec<f64> = slice
+            .iter()
+            .filter_map(|s| s.frequency_mhz)
+            .map(|f| f as f64)
+            .collect();
+        if freqs.is_empty() {
+            None
+        } else {
+            Some(freqs.iter().sum::<f64>() / freqs.len() as f64)
+        }
+    };
+
+    let first_quarter = mean_freq(&samples[..quarter]);
+    let last_quarter = mean_freq(&samples[samples.len() - quarter..]);
+
+    match (first_quarter, last_quarter) {
+        (Some(first), Some(last)) if first > 0.0 => {
+            (first - last) / first > THROTTLE_FREQUENCY_DROP_THRESHOLD
+        }
+        _ => false,
     }
 }
 
@@ -276,20 +1353,78 @@ fn print_result_summary(result: &BenchmarkResult) {
         result.work_label,
         format_number(result.work_units)
     );
-    println!("PI estimate    : {:.12}", result.pi_estimate);
-    println!("Absolute error : {:.12}", result.absolute_error);
+    if result.reports_pi_estimate.unwrap_or(false) {
+        println!("PI estimate    : {:.12}", result.pi_estimate);
+        println!("Absolute error : {:.12}", result.absolute_error);
+    }
     println!("Elapsed        : {:.3} s", result.elapsed_seconds);
     println!(
         "Throughput     : {:.2} {}/s",
         result.throughput_per_second,
         result.work_label.to_lowercase()
     );
+    if let (Some(label), Some(value)) = (
+        &result.secondary_label,
+        result.secondary_throughput_per_second,
+    ) {
+        println!("Secondary      : {:.2} {}", value, label);
+    }
+    if let Some(hex_digits) = &result.hex_digits {
+        println!(
+            "Hex digits     : {hex_digits} (offset {})",
+            result.digit_offset.unwrap_or(0)
+        );
+    }
+    if let Some(thermal) = &result.thermal {
+        if let (Some(start), Some(end)) = (
+            thermal.start_cpu_frequency_mhz,
+            thermal.end_cpu_frequency_mhz,
+        ) {
+            println!("CPU frequency  : {start} MHz -> {end} MHz");
+        }
+        if let Some(mean) = thermal.mean_package_temp_c {
+            println!(
+                "Package temp   : min {:.1}C / mean {:.1}C / max {:.1}C (peak {:.1}C)",
+                thermal.min_package_temp_c.unwrap_or(mean),
+                mean,
+                thermal.max_package_temp_c.unwrap_or(mean),
+                thermal.peak_component_temp_c.unwrap_or(mean)
+            );
+        }
+        if thermal.throttling_detected {
+            println!("Thermal        : throttling detected");
+        }
+    }
+    if let Some(summary) = result.summary.as_ref().filter(|s| s.measured_passes > 1) {
+        println!(
+            "Passes         : {} measured, {} warm-up (discarded)",
+            summary.measured_passes, summary.warmup_passes
+        );
+        print_metric_summary("Elapsed (s)", &summary.elapsed_seconds);
+        print_metric_summary(
+            &format!("{}/s", result.work_label),
+            &summary.throughput_per_second,
+        );
+    }
     if let Some(notes) = &result.notes {
         println!("Notes          : {}", notes);
     }
     println!("Recorded at    : {}", result.timestamp_utc);
 }
 
+fn print_metric_summary(label: &str, summary: &MetricSummary) {
+    println!(
+        "{:<15}: min {:.6} / mean {:.6} / median {:.6} / max {:.6} (stddev {:.6}, cv {:.2}%)",
+        label,
+        summary.min,
+        summary.mean,
+        summary.median,
+        summary.max,
+        summary.std_dev,
+        summary.coefficient_of_variation * 100.0
+    );
+}
+
 fn split_work(total: u64, buckets: u64) -> (u64, u64) {
     (total / buckets, total % buckets)
 }
@@ -322,6 +1457,12 @@ fn parse_usize(value: &str, flag: &str) -> Result<usize, String> {
         .map_err(|_| format!("Could not parse value for {flag}: {value}"))
 }
 
+fn parse_f64(value: &str, flag: &str) -> Result<f64, String> {
+    value
+        .parse::<f64>()
+        .map_err(|_| format!("Could not parse value for {flag}: {value}"))
+}
+
 fn format_number(value: u64) -> String {
     let mut s = value.to_string();
     let mut out = String::new();
@@ -351,6 +1492,12 @@ fn print_global_usage() {
     println!("Modes:");
     println!("  single        Single-threaded Leibniz series");
     println!("  monte         Multi-threaded Monte Carlo (embarrassingly parallel)");
+    println!("  cpu           CPU hashing/arithmetic kernel (ops/sec)");
+    println!("  memory        Memory bandwidth via large-buffer copies (bytes/sec)");
+    println!("  disk          Sequential disk write/read throughput (MiB/sec)");
+    println!("  all           Runs every benchmark and prints a composite hardware profile");
+    println!("  bbp           Bailey-Borwein-Plouffe hex digit extraction (verifiable pi digits)");
+    println!("  compare       Checks a saved JSON history for throughput regressions");
     println!();
     println!("Run `pi-benchmark <mode> --help` for mode-specific options.");
 }
@@ -359,34 +1506,120 @@ fn print_mode_usage(mode: &str) {
     match mode {
         "single" | "leibniz" => print_single_usage(),
         "monte" | "monte-carlo" | "multi" | "multi-thread" => print_monte_usage(),
+        "cpu" => print_cpu_usage(),
+        "memory" | "mem" => print_memory_usage(),
+        "disk" => print_disk_usage(),
+        "all" => print_all_usage(),
+        "bbp" => print_bbp_usage(),
+        "compare" => print_compare_usage(),
         _ => print_global_usage(),
     }
 }
 
 fn print_single_usage() {
     println!(
-        "Usage: pi-benchmark single [--iterations <u64>] [--save-json <path>] [--notes <string>]"
+        "Usage: pi-benchmark single [--iterations <u64>] [--repeat <usize>] [--warmup <usize>] [--save-json <path>] [--notes <string>]"
     );
     println!(
         "  --iterations, -n   Number of Leibniz iterations (default {DEFAULT_LEIBNIZ_ITERATIONS})"
     );
+    println!("  --repeat           Number of measured passes (default 1); summary statistics are printed when > 1");
+    println!("  --warmup           Number of discarded warm-up passes run before the measured passes (default 0)");
     println!("  --save-json        Optional file to append this run as JSON (directories created automatically)");
     println!("  --notes            Free-form text describing the run (e.g. \"Before heatsink replacement\")");
 }
 
 fn print_monte_usage() {
     println!(
-        "Usage: pi-benchmark monte [--samples <u64>] [--threads <usize>] [--seed <u64>] [--save-json <path>] [--notes <string>]"
+        "Usage: pi-benchmark monte [--samples <u64>] [--threads <usize>] [--seed <u64>] [--repeat <usize>] [--warmup <usize>] [--save-json <path>] [--notes <string>]"
     );
     println!(
         "  --samples, -s   Total random points to generate (default {DEFAULT_MONTE_CARLO_SAMPLES})"
     );
     println!("  --threads, -t   Number of worker threads (default: system parallelism)");
     println!("  --seed          Optional RNG seed for reproducibility");
+    println!("  --repeat        Number of measured passes (default 1); summary statistics are printed when > 1");
+    println!("  --warmup        Number of discarded warm-up passes run before the measured passes (default 0)");
     println!("  --save-json     Optional file to append this run as JSON (directories created automatically)");
     println!("  --notes         Free-form text describing the run (e.g. \"Before heatsink replacement\")");
 }
 
+fn print_cpu_usage() {
+    println!(
+        "Usage: pi-benchmark cpu [--duration <seconds>] [--save-json <path>] [--notes <string>]"
+    );
+    println!(
+        "  --duration    Wall-clock budget for the hashing kernel, in seconds (default {DEFAULT_HARDWARE_BENCH_SECONDS})"
+    );
+    println!("  --save-json   Optional file to append this run as JSON (directories created automatically)");
+    println!("  --notes       Free-form text describing the run");
+}
+
+fn print_memory_usage() {
+    println!(
+        "Usage: pi-benchmark memory [--duration <seconds>] [--save-json <path>] [--notes <string>]"
+    );
+    println!(
+        "  --duration    Wall-clock budget for the copy loop, in seconds (default {DEFAULT_HARDWARE_BENCH_SECONDS})"
+    );
+    println!("  --save-json   Optional file to append this run as JSON (directories created automatically)");
+    println!("  --notes       Free-form text describing the run");
+}
+
+fn print_disk_usage() {
+    println!(
+        "Usage: pi-benchmark disk [--duration <seconds>] [--save-json <path>] [--notes <string>]"
+    );
+    println!(
+        "  --duration    Wall-clock budget for the write pass, in seconds (default {DEFAULT_HARDWARE_BENCH_SECONDS}); the read-back pass always reads the whole file"
+    );
+    println!("  --save-json   Optional file to append this run as JSON (directories created automatically)");
+    println!("  --notes       Free-form text describing the run");
+}
+
+fn print_all_usage() {
+    println!(
+        "Usage: pi-benchmark all [--duration <seconds>] [--save-json <path>] [--notes <string>]"
+    );
+    println!("  --duration    Wall-clock budget for each hardware micro-benchmark, in seconds (default {DEFAULT_HARDWARE_BENCH_SECONDS})");
+    println!("  --save-json   Optional file to append every run in the suite as JSON");
+    println!("  --notes       Free-form text applied to every run in the suite");
+}
+
+fn print_bbp_usage() {
+    println!(
+        "Usage: pi-benchmark bbp [--digit-offset <u64>] [--digit-count <u64>] [--save-json <path>] [--notes <string>]"
+    );
+    println!("  --digit-offset   Hex digit position to start extraction at, 0-indexed after \"3.\" (default 0)");
+    println!("  --digit-count    Number of consecutive hex digits to extract (default 16)");
+    println!("  --save-json      Optional file to append this run as JSON (directories created automatically)");
+    println!("  --notes          Free-form text describing the run");
+}
+
+fn print_compare_usage() {
+    println!(
+        "Usage: pi-benchmark compare <results.json> [--baseline <timestamp>] [--baseline-notes <string>] [--threshold <percent>] [--markdown]"
+    );
+    println!("  <results.json>     Path to a JSON history file produced via --save-json");
+    println!(
+        "  --baseline         Compare against the run with this exact timestamp_utc instead of the previous run"
+    );
+    println!(
+        "  --baseline-notes   Compare against the most recent prior run whose --notes match this string"
+    );
+    println!(
+        "  --threshold        Regression threshold as a percent throughput drop (default {DEFAULT_REGRESSION_THRESHOLD_PERCENT})"
+    );
+    println!(
+        "  --markdown         Print the comparison as a markdown table suitable for a PR comment"
+    );
+    println!();
+    println!(
+        "Runs are grouped by (mode, system.cpu_model); each group compares its most recent run against a baseline."
+    );
+    println!("Exits with a non-zero status if any group regresses beyond the threshold.");
+}
+
 fn current_timestamp() -> String {
     let now: DateTime<Utc> = Utc::now();
     now.to_rfc3339_opts(SecondsFormat::Millis, true)
@@ -435,26 +1668,7 @@ fn save_result_if_requested(
                 .map_err(|e| format!("Could not create directory for {path_str}: {e}"))?;
         }
 
-        let mut existing: Vec<BenchmarkResult> = if path.exists() {
-            let contents = fs::read_to_string(path)
-                .map_err(|e| format!("Could not read existing JSON file {path_str}: {e}"))?;
-
-            if contents.trim().is_empty() {
-                Vec::new()
-            } else {
-                serde_json::from_str::<Vec<BenchmarkResult>>(&contents)
-                    .or_else(|_| {
-                        serde_json::from_str::<BenchmarkResult>(&contents)
-                            .map(|single| vec![single])
-                    })
-                    .map_err(|e| {
-                        format!("Could not parse existing JSON file {path_str} as benchmark results: {e}")
-                    })?
-            }
-        } else {
-            Vec::new()
-        };
-
+        let mut existing = load_benchmark_history(path_str)?;
         existing.push(result.clone());
 
         let payload = serde_json::to_string_pretty(&existing)
@@ -469,6 +1683,27 @@ fn save_result_if_requested(
     Ok(())
 }
 
+// Accepts either a `Vec<BenchmarkResult>` array or a single bare object; missing/empty means no history.
+fn load_benchmark_history(path_str: &str) -> Result<Vec<BenchmarkResult>, String> {
+    let path = Path::new(path_str);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Could not read existing JSON file {path_str}: {e}"))?;
+
+    if contents.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    serde_json::from_str::<Vec<BenchmarkResult>>(&contents)
+        .or_else(|_| serde_json::from_str::<BenchmarkResult>(&contents).map(|single| vec![single]))
+        .map_err(|e| {
+            format!("Could not parse existing JSON file {path_str} as benchmark results: {e}")
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -485,4 +1720,164 @@ mod tests {
         let hits = monte_carlo_hits(1_000, 12345);
         assert!(hits <= 1_000);
     }
+
+    #[test]
+    fn bbp_hex_digit_matches_known_pi_expansion() {
+        // The first 16 hex digits of pi's fractional part, well known from
+        // their use as the Blowfish P-array constants (0x243f6a88 0x85a308d3).
+        let known = "243f6a8885a308d3";
+        let digits: String = (0..known.len() as u64)
+            .map(|d| std::char::from_digit(bbp_hex_digit(d) as u32, 16).unwrap())
+            .collect();
+        assert_eq!(digits, known);
+    }
+
+    #[test]
+    fn summarize_metric_singleton() {
+        let summary = summarize_metric(&[4.0]);
+        assert_eq!(summary.min, 4.0);
+        assert_eq!(summary.max, 4.0);
+        assert_eq!(summary.mean, 4.0);
+        assert_eq!(summary.median, 4.0);
+        assert_eq!(summary.std_dev, 0.0);
+        assert_eq!(summary.coefficient_of_variation, 0.0);
+    }
+
+    #[test]
+    fn summarize_metric_even_count() {
+        let summary = summarize_metric(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(summary.min, 1.0);
+        assert_eq!(summary.max, 4.0);
+        assert_eq!(summary.mean, 2.5);
+        assert_eq!(summary.median, 2.5);
+        assert!((summary.std_dev - 1.1180339887).abs() < 1e-9);
+    }
+
+    #[test]
+    fn detect_throttling_needs_at_least_four_samples() {
+        let samples = vec![
+            ThermalSample {
+                package_temp_c: None,
+                peak_temp_c: None,
+                frequency_mhz: Some(2000),
+            };
+            3
+        ];
+        assert!(!detect_throttling(&samples));
+    }
+
+    #[test]
+    fn detect_throttling_flags_sustained_frequency_drop() {
+        let freqs = [2000, 2000, 2000, 2000, 1600, 1600, 1600, 1600];
+        let samples: Vec<ThermalSample> = freqs
+            .iter()
+            .map(|f| ThermalSample {
+                package_temp_c: None,
+                peak_temp_c: None,
+                frequency_mhz: Some(*f),
+            })
+            .collect();
+        assert!(detect_throttling(&samples));
+    }
+
+    #[test]
+    fn detect_throttling_ignores_stable_frequency() {
+        let samples: Vec<ThermalSample> = (0..8)
+            .map(|_| ThermalSample {
+                package_temp_c: None,
+                peak_temp_c: None,
+                frequency_mhz: Some(2000),
+            })
+            .collect();
+        assert!(!detect_throttling(&samples));
+    }
+
+    fn hardware_result_at(timestamp: &str, throughput: f64, notes: Option<&str>) -> BenchmarkResult {
+        BenchmarkResult {
+            timestamp_utc: timestamp.to_string(),
+            mode: "Memory bandwidth (buffer copy)".to_string(),
+            work_label: "Bytes".to_string(),
+            work_units: throughput as u64,
+            reports_pi_estimate: Some(false),
+            pi_estimate: 0.0,
+            absolute_error: 0.0,
+            elapsed_seconds: 1.0,
+            throughput_per_second: throughput,
+            system: SystemProfile {
+                os_name: None,
+                kernel_version: None,
+                cpu_model: Some("Test CPU".to_string()),
+                cpu_architecture: "test".to_string(),
+                cpu_frequency_mhz: None,
+                logical_cores: 1,
+                physical_cores: None,
+                total_memory_bytes: 0,
+                available_memory_bytes: 0,
+                hardware_type_guess: None,
+            },
+            thermal: None,
+            secondary_label: None,
+            secondary_throughput_per_second: None,
+            samples: None,
+            summary: None,
+            notes: notes.map(str::to_string),
+            digit_offset: None,
+            hex_digits: None,
+        }
+    }
+
+    #[test]
+    fn find_baseline_defaults_to_immediately_preceding_run() {
+        let older = hardware_result_at("2026-01-01T00:00:00Z", 100.0, None);
+        let newer = hardware_result_at("2026-01-02T00:00:00Z", 110.0, None);
+        let current = hardware_result_at("2026-01-03T00:00:00Z", 120.0, None);
+        let entries = vec![&older, &newer, &current];
+
+        let baseline = find_baseline(&entries, &current, None, None).unwrap();
+        assert_eq!(baseline.timestamp_utc, newer.timestamp_utc);
+    }
+
+    #[test]
+    fn find_baseline_honors_explicit_timestamp() {
+        let older = hardware_result_at("2026-01-01T00:00:00Z", 100.0, None);
+        let newer = hardware_result_at("2026-01-02T00:00:00Z", 110.0, None);
+        let current = hardware_result_at("2026-01-03T00:00:00Z", 120.0, None);
+        let entries = vec![&older, &newer, &current];
+
+        let baseline = find_baseline(&entries, &current, Some("2026-01-01T00:00:00Z"), None).unwrap();
+        assert_eq!(baseline.timestamp_utc, older.timestamp_utc);
+    }
+
+    #[test]
+    fn find_baseline_honors_baseline_notes() {
+        let tagged = hardware_result_at("2026-01-01T00:00:00Z", 100.0, Some("release"));
+        let untagged = hardware_result_at("2026-01-02T00:00:00Z", 110.0, None);
+        let current = hardware_result_at("2026-01-03T00:00:00Z", 120.0, None);
+        let entries = vec![&tagged, &untagged, &current];
+
+        let baseline = find_baseline(&entries, &current, None, Some("release")).unwrap();
+        assert_eq!(baseline.timestamp_utc, tagged.timestamp_utc);
+    }
+
+    #[test]
+    fn build_comparisons_flags_regression_past_threshold() {
+        let baseline = hardware_result_at("2026-01-01T00:00:00Z", 100.0, None);
+        let regressed = hardware_result_at("2026-01-02T00:00:00Z", 90.0, None);
+        let history = vec![baseline, regressed];
+
+        let comparisons = build_comparisons(&history, None, None, 5.0);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].verdict, RegressionVerdict::Regression);
+    }
+
+    #[test]
+    fn build_comparisons_passes_within_threshold() {
+        let baseline = hardware_result_at("2026-01-01T00:00:00Z", 100.0, None);
+        let steady = hardware_result_at("2026-01-02T00:00:00Z", 98.0, None);
+        let history = vec![baseline, steady];
+
+        let comparisons = build_comparisons(&history, None, None, 5.0);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].verdict, RegressionVerdict::Pass);
+    }
 }